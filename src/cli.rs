@@ -14,10 +14,14 @@
 // You should have received a copy of the GNU General Public License
 // along with polkadot-rewards.  If not, see <http://www.gnu.org/licenses/>.
 
-use crate::{api::Api, primitives::CsvRecord};
-use anyhow::{anyhow, bail, Context, Error};
+use crate::{
+	api::Api,
+	primitives::{CurrencyValue, RewardRow},
+};
+use anyhow::{bail, Context, Error};
 use argh::FromArgs;
-use chrono::{naive::NaiveDateTime, offset::Utc};
+use chrono::{naive::NaiveDateTime, offset::Utc, DateTime, TimeZone};
+use chrono_tz::Tz;
 use env_logger::{Builder, Env};
 use indicatif::{ProgressBar, ProgressStyle};
 use std::{fs::File, io, path::PathBuf, str::FromStr};
@@ -26,6 +30,21 @@ const OUTPUT_DATE: &str = "%Y-%m-%d";
 
 #[derive(FromArgs, PartialEq, Debug)]
 /// Polkadot Staking Rewards CLI-App
+pub struct Opt {
+	#[argh(subcommand)]
+	command: Command,
+}
+
+#[derive(FromArgs, PartialEq, Debug)]
+#[argh(subcommand)]
+enum Command {
+	Fetch(App),
+	Range(RangeCommand),
+}
+
+#[derive(FromArgs, PartialEq, Debug)]
+#[argh(subcommand, name = "fetch")]
+/// Crawl the network for staking rewards.
 pub struct App {
 	#[argh(option, from_str_fn(date_from_string), short = 'f')]
 	/// date to start crawling for staking rewards. Format: "YYY-MM-DD HH:MM:SS"
@@ -36,26 +55,68 @@ pub struct App {
 	/// network to crawl for rewards. One of: [Polkadot, Kusama, KSM, DOT]
 	#[argh(option, default = "Network::Polkadot", short = 'n')]
 	pub network: Network,
-	/// the fiat currency which should be used for prices
-	#[argh(option, short = 'c')]
-	pub currency: String,
+	/// comma-separated fiat currencies to price rewards in (EX: "usd,eur,btc"). Each gets its own
+	/// price/value column in the output; a currency the price oracle doesn't recognize produces
+	/// a warning and an empty column rather than aborting the whole run.
+	#[argh(option, short = 'c', from_str_fn(currencies_from_string))]
+	pub currency: Vec<String>,
 	/// network-formatted address to get staking rewards for.
 	#[argh(option, short = 'a')]
 	pub address: String,
 	/// date format to use in output CSV data. Uses rfc2822 by default.  EX: "%Y-%m-%d %H:%M:%S".
 	#[argh(option, default = "OUTPUT_DATE.to_string()")]
 	date_format: String,
+	/// timezone to render `block_time` in, as an IANA name (EX: "Europe/Berlin"). Defaults to UTC.
+	#[argh(option, default = "Tz::UTC", short = 'z')]
+	timezone: Tz,
+	/// output format: "csv" (default, semicolon-delimited) or "postgres" (tab-separated,
+	/// `COPY ... WITH (FORMAT text)`-ready, missing prices rendered as `\N`).
+	#[argh(option, default = "Format::Csv")]
+	format: Format,
 	/// directory to output completed CSV to.
 	#[argh(option, default = "default_file_location()", short = 'p')]
 	folder: PathBuf,
 	/// output the CSV file to STDOUT. Disables creating a new file.
 	#[argh(switch, short = 's')]
 	stdout: bool,
+	/// keep a dated, reproducible history of runs instead of one flat file: writes to
+	/// `<folder>/<address>/<unix_timestamp>/rewards.<ext>` alongside a `run.json` manifest
+	/// recording the parameters the run was made with. Incompatible with `--stdout`.
+	#[argh(switch)]
+	archive: bool,
 	/// get extra information about the program's execution.
 	#[argh(switch, short = 'v')]
 	verbose: bool,
 }
 
+#[derive(FromArgs, PartialEq, Debug)]
+#[argh(subcommand, name = "range")]
+/// Re-slice a previously generated rewards CSV by `block_time`, without touching the network.
+struct RangeCommand {
+	/// rewards CSV to read from, as written by `fetch` (semicolon-delimited).
+	#[argh(option, short = 'i')]
+	input: PathBuf,
+	/// start of the range to keep (inclusive). Same formats as `fetch --from`.
+	#[argh(option, from_str_fn(date_from_string))]
+	start: NaiveDateTime,
+	/// end of the range to keep (inclusive). Same formats as `fetch --to`.
+	#[argh(option, from_str_fn(date_from_string))]
+	end: NaiveDateTime,
+	/// the `block_time` format `input` was written with. Must match whatever `--date-format`
+	/// the original `fetch` run used.
+	#[argh(option, default = "OUTPUT_DATE.to_string()")]
+	date_format: String,
+	/// the timezone `input`'s `block_time` column was rendered in, as an IANA name (EX:
+	/// "Europe/Berlin"). Must match whatever `--timezone` the original `fetch` run used (recorded
+	/// alongside the CSV in `run.json` for `--archive` runs); `--start`/`--end` are always UTC, so
+	/// getting this wrong silently keeps or drops rows by the timezone offset. Defaults to UTC.
+	#[argh(option, default = "Tz::UTC", short = 'z')]
+	timezone: Tz,
+	/// where to write the sliced rows. Defaults to STDOUT.
+	#[argh(option, short = 'o')]
+	output: Option<PathBuf>,
+}
+
 fn default_date() -> NaiveDateTime {
 	Utc::now().naive_utc()
 }
@@ -72,12 +133,33 @@ fn default_file_location() -> PathBuf {
 
 // we don't return an anyhow::Error here because `argh` macro expects error type to be a `String`
 pub fn date_from_string(value: &str) -> Result<chrono::NaiveDateTime, String> {
-	let time = match NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M:%S") {
+	// RFC3339 strings (EX: "2021-03-01T14:00:00Z" or "2021-03-01T14:00:00+02:00") carry their
+	// own offset, so normalize to UTC before discarding it. Block explorers tend to hand these
+	// out, so try them first and fall back to the fixed local format for backward compatibility.
+	if let Ok(t) = DateTime::parse_from_rfc3339(value) {
+		return Ok(t.with_timezone(&Utc).naive_utc());
+	}
+	match NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M:%S") {
 		Ok(t) => Ok(t),
 		Err(e) => Err(e.to_string()),
-	};
-	let time = time?;
-	Ok(time)
+	}
+}
+
+fn currencies_from_string(value: &str) -> Result<Vec<String>, String> {
+	let currencies: Vec<String> = value.split(',').map(|c| c.trim().to_lowercase()).filter(|c| !c.is_empty()).collect();
+	if currencies.is_empty() {
+		return Err("--currency must list at least one fiat currency".to_string());
+	}
+	Ok(currencies)
+}
+
+/// Parses a `block_time` column value written with `format`. Most `--date-format`s carry no time
+/// component (the default, `OUTPUT_DATE`, is a bare `%Y-%m-%d`), and `NaiveDateTime::parse_from_str`
+/// rejects those outright ("input not enough for a unique date and time"), so fall back to
+/// `NaiveDate` and anchor it at midnight.
+fn parse_block_time(value: &str, format: &str) -> Result<NaiveDateTime, chrono::ParseError> {
+	NaiveDateTime::parse_from_str(value, format)
+		.or_else(|_| chrono::NaiveDate::parse_from_str(value, format).map(|d| d.and_hms(0, 0, 0)))
 }
 
 #[derive(PartialEq, Debug)]
@@ -95,6 +177,14 @@ impl Network {
 			Self::Kusama => "kusama",
 		}
 	}
+
+	/// The CoinGecko coin id to fetch historical prices under.
+	pub fn coingecko_id(&self) -> &'static str {
+		match self {
+			Self::Polkadot => "polkadot",
+			Self::Kusama => "kusama",
+		}
+	}
 }
 
 impl FromStr for Network {
@@ -108,8 +198,34 @@ impl FromStr for Network {
 	}
 }
 
+#[derive(PartialEq, Debug)]
+pub enum Format {
+	/// Semicolon-delimited CSV, the historical default.
+	Csv,
+	/// Tab-separated text ready for `COPY rewards FROM '...' WITH (FORMAT text)`.
+	Postgres,
+}
+
+impl FromStr for Format {
+	type Err = Error;
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s.to_lowercase().as_str() {
+			"csv" => Ok(Format::Csv),
+			"postgres" | "pg" => Ok(Format::Postgres),
+			_ => bail!("Format must be one of: 'csv', 'postgres'"),
+		}
+	}
+}
+
 pub fn app() -> Result<(), Error> {
-	let mut app: App = argh::from_env();
+	let opt: Opt = argh::from_env();
+	match opt.command {
+		Command::Fetch(app) => fetch(app),
+		Command::Range(cmd) => range(cmd),
+	}
+}
+
+fn fetch(mut app: App) -> Result<(), Error> {
 	let progress = if app.verbose {
 		Builder::from_env(Env::default().default_filter_or("info")).init();
 		None
@@ -123,32 +239,124 @@ pub fn app() -> Result<(), Error> {
 		.context("Failed to fetch rewards.")?;
 	let prices = api.fetch_prices(&rewards).context("Failed to fetch prices.")?;
 
-	let file_name = construct_file_name(&app);
-	app.folder.push(&file_name);
-	app.folder.set_extension("csv");
+	let extension = match app.format {
+		Format::Csv => "csv",
+		Format::Postgres => "tsv",
+	};
+	if app.archive {
+		if app.stdout {
+			bail!("--archive cannot be combined with --stdout");
+		}
+		// Keyed by the second the directory is created in (after fetching), so two `--archive` runs within the same second (a
+		// scripted backfill loop, a retry) would otherwise silently share a directory and
+		// overwrite each other's manifest/rewards file. Fail instead of clobbering history.
+		let run_dir = app.folder.join(&app.address).join(Utc::now().timestamp().to_string());
+		if run_dir.exists() {
+			bail!(
+				"Archive run directory '{}' already exists from an earlier run this same second; retry in a moment.",
+				run_dir.display()
+			);
+		}
+		std::fs::create_dir_all(&run_dir).context("Failed to create archive run directory.")?;
+		write_run_manifest(&run_dir, &app).context("Failed to write run manifest.")?;
+		app.folder = run_dir.join("rewards");
+	} else {
+		app.folder.push(construct_file_name(&app));
+	}
+	app.folder.set_extension(extension);
 
 	let mut wtr = Output::new(&app).context("Failed to create output.")?;
+	if let Format::Csv = app.format {
+		wtr.write_csv_header(&app.currency).context("Failed to write CSV header.")?;
+	}
 
 	for (reward, price) in rewards.iter().zip(prices.iter()) {
-		wtr.serialize(CsvRecord {
-			block_num: reward.block_num,
-			block_time: reward.day.format(&app.date_format).to_string(),
-			amount: amount_to_network(&app.network, &reward.amount),
-			price: *price.market_data.current_price.get(&app.currency).ok_or_else(|| {
-				anyhow!(
-					"Specified fiat currency '{}' not supported: {:#?}",
-					app.currency,
-					price.market_data.current_price.keys(),
-				)
-			})?,
-		})
-		.context("Failed to format CsvRecord")?;
+		let block_time = reward.day.with_timezone(&app.timezone).format(&app.date_format).to_string();
+		let amount = amount_to_network(&app.network, &reward.amount);
+
+		let currencies = app
+			.currency
+			.iter()
+			.map(|currency| {
+				let price_in_currency = price.market_data.current_price.get(currency).copied();
+				if price_in_currency.is_none() {
+					log::warn!(
+						"Specified fiat currency '{}' not supported for block {}: {:#?}",
+						currency,
+						reward.block_num,
+						price.market_data.current_price.keys(),
+					);
+				}
+				CurrencyValue { currency: currency.clone(), price: price_in_currency, value: price_in_currency.map(|p| p * amount) }
+			})
+			.collect();
+
+		let row = RewardRow { block_num: reward.block_num, block_time, amount, currencies };
+		match app.format {
+			Format::Csv => wtr.write_csv_row(&row).context("Failed to write CSV row.")?,
+			Format::Postgres => wtr.write_postgres(&row).context("Failed to write Postgres row.")?,
+		}
 	}
 
 	if app.stdout {
 		progress.map(|p| p.finish_with_message("Writing data to STDOUT"));
 	} else {
-		progress.map(|p| p.finish_with_message(&format!("wrote data to file {}", &file_name)));
+		progress.map(|p| p.finish_with_message(&format!("wrote data to file {}", app.folder.display())));
+	}
+	Ok(())
+}
+
+/// Streams `cmd.input` and writes out only the rows whose `block_time` falls within
+/// `[cmd.start, cmd.end]`. Rows are written in ascending block order, so this stops reading as
+/// soon as a row's `block_time` exceeds `cmd.end` instead of scanning the whole file.
+///
+/// `block_time` is parsed in `cmd.timezone` (the zone `fetch --timezone` rendered it in) and
+/// converted to UTC before comparing, since `cmd.start`/`cmd.end` are always UTC.
+fn range(cmd: RangeCommand) -> Result<(), Error> {
+	let mut rdr = csv::ReaderBuilder::new().delimiter(b';').from_path(&cmd.input).context("Failed to open input CSV.")?;
+	let mut wtr = match &cmd.output {
+		Some(path) => {
+			let mut builder = csv::WriterBuilder::new();
+			builder.delimiter(b';').has_headers(false);
+			Output::FileOut(builder.from_path(path)?)
+		}
+		None => {
+			let mut builder = csv::WriterBuilder::new();
+			builder.delimiter(b';').has_headers(false);
+			Output::StdOut(builder.from_writer(io::stdout()))
+		}
+	};
+
+	// The column layout (how many currencies were fetched with) doesn't matter here: rows are
+	// passed through unchanged, we only need to know which column holds `block_time`.
+	let headers = rdr.headers().context("Input CSV has no header row.")?.clone();
+	let block_time_col =
+		headers.iter().position(|h| h == "block_time").context("Input CSV has no 'block_time' column.")?;
+	wtr.write_record(&headers).context("Failed to write the output header.")?;
+
+	for result in rdr.records() {
+		let record = result.context("Failed to parse a row of the input CSV.")?;
+		let raw_block_time = record.get(block_time_col).context("Row is missing its block_time column.")?;
+		let local_block_time = parse_block_time(raw_block_time, &cmd.date_format)
+			.with_context(|| format!("Failed to parse block_time '{}' with format '{}'.", raw_block_time, cmd.date_format))?;
+		// `block_time` was rendered in `cmd.timezone` by the original `fetch` run, but `--start`/
+		// `--end` are always UTC (see `date_from_string`), so convert before comparing.
+		let block_time = cmd
+			.timezone
+			.from_local_datetime(&local_block_time)
+			.single()
+			.with_context(|| {
+				format!("block_time '{}' is ambiguous or invalid in timezone '{}'.", raw_block_time, cmd.timezone)
+			})?
+			.with_timezone(&Utc)
+			.naive_utc();
+		if block_time < cmd.start {
+			continue;
+		}
+		if block_time > cmd.end {
+			break;
+		}
+		wtr.write_record(&record).context("Failed to write a row of the output CSV.")?;
 	}
 	Ok(())
 }
@@ -170,6 +378,41 @@ fn amount_to_network(network: &Network, amount: &u128) -> f64 {
 	}
 }
 
+/// The exact parameters a `--archive` run was made with, so a dated snapshot is reproducible
+/// without having to remember (or dig up) the command line that produced it.
+#[derive(serde::Serialize)]
+struct RunManifest<'a> {
+	network: &'a str,
+	address: &'a str,
+	from: NaiveDateTime,
+	to: NaiveDateTime,
+	currency: &'a [String],
+	date_format: &'a str,
+	timezone: String,
+	format: &'a str,
+	version: &'static str,
+}
+
+fn write_run_manifest(run_dir: &std::path::Path, app: &App) -> Result<(), Error> {
+	let manifest = RunManifest {
+		network: app.network.id(),
+		address: &app.address,
+		from: app.from,
+		to: app.to,
+		currency: &app.currency,
+		date_format: &app.date_format,
+		timezone: app.timezone.to_string(),
+		format: match app.format {
+			Format::Csv => "csv",
+			Format::Postgres => "postgres",
+		},
+		version: env!("CARGO_PKG_VERSION"),
+	};
+	let file = File::create(run_dir.join("run.json"))?;
+	serde_json::to_writer_pretty(file, &manifest)?;
+	Ok(())
+}
+
 // constructs a file name in the format: `dot-address-from_date-to_date-rewards.csv`
 fn construct_file_name(app: &App) -> String {
 	format!(
@@ -184,25 +427,173 @@ fn construct_file_name(app: &App) -> String {
 enum Output {
 	FileOut(csv::Writer<File>),
 	StdOut(csv::Writer<std::io::Stdout>),
+	PostgresFile(File),
+	PostgresStdOut(std::io::Stdout),
 }
 
 impl Output {
 	fn new(app: &App) -> Result<Self, Error> {
-		let mut builder = csv::WriterBuilder::new();
-		builder.delimiter(b';');
-		if app.stdout {
-			Ok(Output::StdOut(builder.from_writer(io::stdout())))
-		} else {
-			let file = File::create(&app.folder)?;
-			Ok(Output::FileOut(builder.from_writer(file)))
+		match app.format {
+			Format::Csv => {
+				let mut builder = csv::WriterBuilder::new();
+				builder.delimiter(b';').has_headers(false);
+				if app.stdout {
+					Ok(Output::StdOut(builder.from_writer(io::stdout())))
+				} else {
+					let file = File::create(&app.folder)?;
+					Ok(Output::FileOut(builder.from_writer(file)))
+				}
+			}
+			Format::Postgres => {
+				if app.stdout {
+					Ok(Output::PostgresStdOut(io::stdout()))
+				} else {
+					Ok(Output::PostgresFile(File::create(&app.folder)?))
+				}
+			}
 		}
 	}
 
-	fn serialize<T: serde::Serialize>(&mut self, val: T) -> Result<(), Error> {
+	/// Writes a plain row of already-formatted fields, used directly by `range` to pass rows
+	/// through unchanged, and by [`Output::write_csv_row`] for formatted reward rows.
+	fn write_record<I, T>(&mut self, fields: I) -> Result<(), Error>
+	where
+		I: IntoIterator<Item = T>,
+		T: AsRef<[u8]>,
+	{
 		match self {
-			Output::FileOut(f) => f.serialize(val)?,
-			Output::StdOut(s) => s.serialize(val)?,
+			Output::FileOut(f) => f.write_record(fields)?,
+			Output::StdOut(s) => s.write_record(fields)?,
+			Output::PostgresFile(_) | Output::PostgresStdOut(_) => bail!("write_record() is not supported for Postgres output; use write_postgres()"),
 		};
 		Ok(())
 	}
+
+	/// Writes the CSV header: `block_num;block_time;amount` followed by a `price_<currency>` and
+	/// `value_<currency>` column pair per requested currency.
+	fn write_csv_header(&mut self, currencies: &[String]) -> Result<(), Error> {
+		let mut header = vec!["block_num".to_string(), "block_time".to_string(), "amount".to_string()];
+		for currency in currencies {
+			header.push(format!("price_{}", currency));
+			header.push(format!("value_{}", currency));
+		}
+		self.write_record(header)
+	}
+
+	/// Writes one reward row, one `price_<currency>`/`value_<currency>` column pair at a time.
+	fn write_csv_row(&mut self, row: &RewardRow) -> Result<(), Error> {
+		let mut fields = vec![row.block_num.to_string(), row.block_time.clone(), row.amount.to_string()];
+		for currency in &row.currencies {
+			fields.push(currency.price.map(|p| p.to_string()).unwrap_or_default());
+			fields.push(currency.value.map(|v| v.to_string()).unwrap_or_default());
+		}
+		self.write_record(fields)
+	}
+
+	/// Writes one row as tab-separated, Postgres `text` format-escaped fields, with missing
+	/// values rendered as the literal `\N` NULL token.
+	fn write_postgres(&mut self, row: &RewardRow) -> Result<(), Error> {
+		use io::Write;
+		let mut fields = vec![
+			row.block_num.to_string(),
+			escape_postgres_text(&row.block_time),
+			row.amount.to_string(),
+		];
+		for currency in &row.currencies {
+			fields.push(currency.price.map(|p| p.to_string()).unwrap_or_else(|| "\\N".to_string()));
+			fields.push(currency.value.map(|v| v.to_string()).unwrap_or_else(|| "\\N".to_string()));
+		}
+		let line = format!("{}\n", fields.join("\t"));
+		match self {
+			Output::PostgresFile(f) => f.write_all(line.as_bytes())?,
+			Output::PostgresStdOut(s) => s.write_all(line.as_bytes())?,
+			Output::FileOut(_) | Output::StdOut(_) => bail!("write_postgres() is not supported for CSV output; use write_csv_row()"),
+		}
+		Ok(())
+	}
+}
+
+/// Escapes backslashes, tabs, newlines and carriage returns per the Postgres `COPY ... WITH
+/// (FORMAT text)` text format.
+fn escape_postgres_text(value: &str) -> String {
+	value.replace('\\', "\\\\").replace('\t', "\\t").replace('\n', "\\n").replace('\r', "\\r")
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use std::{fs, io::Write};
+
+	#[test]
+	fn parse_block_time_falls_back_to_date_only() {
+		let parsed = parse_block_time("2021-03-15", OUTPUT_DATE).unwrap();
+		assert_eq!(parsed, chrono::NaiveDate::from_ymd(2021, 3, 15).and_hms(0, 0, 0));
+	}
+
+	/// `range`'s own default `--date-format` matches `fetch`'s, so slicing a CSV produced by an
+	/// ordinary `fetch` (no custom `--date-format`) must work out of the box.
+	#[test]
+	fn range_slices_a_csv_written_with_the_default_date_format() {
+		let dir = std::env::temp_dir();
+		let input = dir.join("polkadot-rewards-test-range-slices-input.csv");
+		let output = dir.join("polkadot-rewards-test-range-slices-output.csv");
+
+		let mut f = fs::File::create(&input).unwrap();
+		writeln!(f, "block_num;block_time;amount").unwrap();
+		writeln!(f, "1;2021-03-10;1.0").unwrap();
+		writeln!(f, "2;2021-03-15;2.0").unwrap();
+		writeln!(f, "3;2021-03-20;3.0").unwrap();
+		drop(f);
+
+		range(RangeCommand {
+			input: input.clone(),
+			start: chrono::NaiveDate::from_ymd(2021, 3, 12).and_hms(0, 0, 0),
+			end: chrono::NaiveDate::from_ymd(2021, 3, 18).and_hms(0, 0, 0),
+			date_format: OUTPUT_DATE.to_string(),
+			timezone: Tz::UTC,
+			output: Some(output.clone()),
+		})
+		.unwrap();
+
+		let kept = fs::read_to_string(&output).unwrap();
+		assert!(kept.contains("2021-03-15"));
+		assert!(!kept.contains("2021-03-10"));
+		assert!(!kept.contains("2021-03-20"));
+
+		fs::remove_file(&input).ok();
+		fs::remove_file(&output).ok();
+	}
+
+	/// A CSV written by `fetch --timezone Asia/Tokyo` renders `block_time` nine hours ahead of
+	/// UTC; `range --start`/`--end` are always UTC, so `range` must convert through `--timezone`
+	/// rather than comparing the two directly, or a row can be kept/dropped a day off.
+	#[test]
+	fn range_converts_block_time_from_its_recorded_timezone() {
+		let dir = std::env::temp_dir();
+		let input = dir.join("polkadot-rewards-test-range-timezone-input.csv");
+		let output = dir.join("polkadot-rewards-test-range-timezone-output.csv");
+		let date_format = "%Y-%m-%d %H:%M:%S";
+
+		// 2021-03-15 02:00:00 JST == 2021-03-14 17:00:00 UTC.
+		let mut f = fs::File::create(&input).unwrap();
+		writeln!(f, "block_num;block_time;amount").unwrap();
+		writeln!(f, "1;2021-03-15 02:00:00;1.0").unwrap();
+		drop(f);
+
+		range(RangeCommand {
+			input: input.clone(),
+			start: chrono::NaiveDate::from_ymd(2021, 3, 14).and_hms(12, 0, 0),
+			end: chrono::NaiveDate::from_ymd(2021, 3, 14).and_hms(20, 0, 0),
+			date_format: date_format.to_string(),
+			timezone: "Asia/Tokyo".parse().unwrap(),
+			output: Some(output.clone()),
+		})
+		.unwrap();
+
+		let kept = fs::read_to_string(&output).unwrap();
+		assert!(kept.contains("2021-03-15 02:00:00"));
+
+		fs::remove_file(&input).ok();
+		fs::remove_file(&output).ok();
+	}
 }
@@ -0,0 +1,61 @@
+// Copyright 2021 Parity Technologies (UK) Ltd.
+// This file is part of polkadot-rewards.
+
+// polkadot-rewards is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// polkadot-rewards is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with polkadot-rewards.  If not, see <http://www.gnu.org/licenses/>.
+
+use chrono::{offset::Utc, DateTime};
+use serde::Deserialize;
+use std::collections::HashMap;
+
+/// A single staking reward payout, as reported by the Subscan indexer.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Reward {
+	/// Block number the reward was paid out in.
+	pub block_num: u32,
+	/// Timestamp of the block, always in UTC. Rendered in the user's chosen timezone on output.
+	pub day: DateTime<Utc>,
+	/// Reward amount, in the chain's smallest unit (Planck).
+	pub amount: u128,
+}
+
+/// Deserialized response from the CoinGecko `coins/{id}/history` endpoint.
+#[derive(Clone, Debug, Deserialize)]
+pub struct Price {
+	pub market_data: MarketData,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct MarketData {
+	pub current_price: HashMap<String, f64>,
+}
+
+/// The resolved price and cost-basis value of a reward in one requested currency. `price` (and
+/// therefore `value`) is `None` when the currency wasn't present in the price oracle's response,
+/// which produces a warning and a `\N`/empty column rather than aborting the whole run.
+#[derive(Clone, Debug)]
+pub struct CurrencyValue {
+	pub currency: String,
+	pub price: Option<f64>,
+	pub value: Option<f64>,
+}
+
+/// One row of an output export, widened with one `(price, value)` pair per `--currency`
+/// requested.
+#[derive(Clone, Debug)]
+pub struct RewardRow {
+	pub block_num: u32,
+	pub block_time: String,
+	pub amount: f64,
+	pub currencies: Vec<CurrencyValue>,
+}